@@ -1,5 +1,9 @@
+use std::cell::{Cell, RefCell};
 use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
 
+use js_sys::Reflect;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{
@@ -7,28 +11,189 @@ use web_sys::{
 };
 use wgpu::{
     Adapter, Device, DeviceDescriptor, Features, Instance, Limits, MemoryHints, PowerPreference,
-    Queue, Surface, SurfaceTarget,
+    Queue, RenderPipeline, Surface, SurfaceConfiguration, SurfaceTarget,
 };
 
+/// The backend a [`WgpuApp`] attempted to initialize, used to identify which
+/// attempt a [`WebcgError`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    WebGpu,
+    WebGl,
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Backend::WebGpu => write!(f, "WebGPU"),
+            Backend::WebGl => write!(f, "WebGL"),
+        }
+    }
+}
+
+/// Errors that can occur while setting up a [`WgpuApp`] or loading assets
+/// with [`load_text`] and [`load_bytes`].
+#[derive(Debug)]
+pub enum WebcgError {
+    /// The browser does not expose `navigator.gpu`, so the WebGPU backend
+    /// cannot be attempted at all.
+    WebGpuUnsupported,
+    /// `Instance::create_surface` failed for the given backend.
+    SurfaceCreation {
+        backend: Backend,
+        source: wgpu::CreateSurfaceError,
+    },
+    /// `Instance::request_adapter` returned `None` for the given backend.
+    NoAdapter { backend: Backend },
+    /// `Adapter::request_device` failed for the given backend.
+    DeviceRequest {
+        backend: Backend,
+        source: wgpu::RequestDeviceError,
+    },
+    /// Both the WebGPU and WebGL backends failed during [`WgpuApp::new`].
+    BackendInitFailed {
+        webgpu: Box<WebcgError>,
+        webgl: Box<WebcgError>,
+    },
+    /// Fetching `url` failed at the network/JS level.
+    FetchFailed { url: String, reason: String },
+    /// The server returned a non-success status code for `url`.
+    UnexpectedStatus { url: String, status: u16 },
+}
+
+impl fmt::Display for WebcgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebcgError::WebGpuUnsupported => {
+                write!(f, "WebGPU is not supported by this browser")
+            }
+            WebcgError::SurfaceCreation { backend, source } => {
+                write!(f, "failed to create {backend} surface: {source}")
+            }
+            WebcgError::NoAdapter { backend } => {
+                write!(f, "no suitable adapter found for {backend} backend")
+            }
+            WebcgError::DeviceRequest { backend, source } => {
+                write!(f, "failed to request {backend} device: {source}")
+            }
+            WebcgError::BackendInitFailed { webgpu, webgl } => {
+                write!(
+                    f,
+                    "WebGPU backend failed ({webgpu}), and WebGL backend failed ({webgl})"
+                )
+            }
+            WebcgError::FetchFailed { url, reason } => {
+                write!(f, "failed to fetch {url}: {reason}")
+            }
+            WebcgError::UnexpectedStatus { url, status } => {
+                write!(f, "unexpected status {status} fetching {url}")
+            }
+        }
+    }
+}
+
+impl Error for WebcgError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WebcgError::SurfaceCreation { source, .. } => Some(source),
+            WebcgError::DeviceRequest { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<WebcgError> for JsValue {
+    fn from(err: WebcgError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Returns `true` if the browser exposes `navigator.gpu`, i.e. WebGPU is
+/// at least nominally available.
+///
+/// This does not guarantee that `request_adapter` will succeed, but lets
+/// callers skip the WebGPU attempt entirely on browsers where `gpu` is
+/// `undefined`, avoiding a throwaway canvas and a confusing `TypeError`
+/// deep inside the backend.
+fn webgpu_available() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+
+    match Reflect::get(&window.navigator(), &JsValue::from_str("gpu")) {
+        Ok(gpu) => !gpu.is_undefined() && !gpu.is_null(),
+        Err(_) => false,
+    }
+}
+
+/// Adapter/device negotiation knobs for [`WgpuApp::new`] and the
+/// backend-specific constructors, mirroring the options exposed by
+/// `RequestAdapterOptions` and `DeviceDescriptor`.
+#[derive(Debug, Clone)]
+pub struct WgpuAppConfig {
+    pub power_preference: PowerPreference,
+    pub required_features: Features,
+    pub required_limits: Limits,
+    pub force_fallback_adapter: bool,
+}
+
+impl Default for WgpuAppConfig {
+    fn default() -> Self {
+        Self {
+            power_preference: PowerPreference::default(),
+            required_features: Features::empty(),
+            required_limits: Limits::downlevel_webgl2_defaults(),
+            force_fallback_adapter: false,
+        }
+    }
+}
+
 pub struct WgpuApp<'a> {
     instance: Instance,
     surface: Box<Surface<'a>>,
     adapter: Adapter,
     device: Device,
     queue: Queue,
+    canvas: HtmlCanvasElement,
+    config: RefCell<Option<SurfaceConfiguration>>,
+    pipeline: RefCell<Option<RenderPipeline>>,
+    desired_size: Cell<(u32, u32)>,
 }
 
 impl<'a> WgpuApp<'a> {
-    pub async fn new(document: Document, parent_element: HtmlElement) -> Result<Self, JsValue> {
+    pub async fn new(
+        document: Document,
+        parent_element: HtmlElement,
+        config: WgpuAppConfig,
+    ) -> Result<Self, JsValue> {
+        if !webgpu_available() {
+            // `navigator.gpu` is absent, so don't even attempt the WebGPU
+            // backend and go straight to WebGL.
+            console::log_1(&"WebGPU is not available, falling back to WebGL backend".into());
+            let canvas = document
+                .create_element("canvas")?
+                .dyn_into::<HtmlCanvasElement>()?;
+            parent_element.append_child(&canvas)?;
+
+            return match Self::with_webgl_backend(canvas, &config).await {
+                Ok(app) => Ok(app),
+                Err(webgl_err) => Err(WebcgError::BackendInitFailed {
+                    webgpu: Box::new(WebcgError::WebGpuUnsupported),
+                    webgl: Box::new(webgl_err),
+                }
+                .into()),
+            };
+        }
+
         // Create a canvas and try with a WebGPU backend.
         let canvas = document
             .create_element("canvas")?
             .dyn_into::<HtmlCanvasElement>()?;
         parent_element.append_child(&canvas)?;
 
-        match Self::with_webgpu_backend(canvas.clone()).await {
+        let webgpu_err = match Self::with_webgpu_backend(canvas.clone(), &config).await {
             Ok(app) => return Ok(app),
-            Err(_) => (),
+            Err(e) => e,
         };
 
         // If WebGPU backend fails, destroy the canvas and try with a WebGL backend.
@@ -38,13 +203,20 @@ impl<'a> WgpuApp<'a> {
             .dyn_into::<HtmlCanvasElement>()?;
         parent_element.append_child(&canvas)?;
 
-        match Self::with_webgl_backend(canvas).await {
-            Ok(app) => return Ok(app),
-            Err(e) => return Err(JsValue::from_str(&e.to_string())),
-        };
+        match Self::with_webgl_backend(canvas, &config).await {
+            Ok(app) => Ok(app),
+            Err(webgl_err) => Err(WebcgError::BackendInitFailed {
+                webgpu: Box::new(webgpu_err),
+                webgl: Box::new(webgl_err),
+            }
+            .into()),
+        }
     }
 
-    pub async fn with_webgpu_backend(canvas: HtmlCanvasElement) -> Result<Self, Box<dyn Error>> {
+    pub async fn with_webgpu_backend(
+        canvas: HtmlCanvasElement,
+        config: &WgpuAppConfig,
+    ) -> Result<Self, WebcgError> {
         let instance_desc = wgpu::InstanceDescriptor {
             backends: wgpu::Backends::BROWSER_WEBGPU,
             flags: wgpu::InstanceFlags::empty(),
@@ -54,33 +226,39 @@ impl<'a> WgpuApp<'a> {
 
         let instance = wgpu::Instance::new(instance_desc);
 
+        let canvas_handle = canvas.clone();
         let surface_target: SurfaceTarget = SurfaceTarget::Canvas(canvas);
         let surface = match instance.create_surface(surface_target) {
             Ok(surface) => surface,
             Err(e) => {
                 console::error_1(&e.to_string().into());
-                return Err(Box::new(e));
+                return Err(WebcgError::SurfaceCreation {
+                    backend: Backend::WebGpu,
+                    source: e,
+                });
             }
         };
 
         let request_adapter_options = wgpu::RequestAdapterOptions {
-            power_preference: PowerPreference::default(),
+            power_preference: config.power_preference,
             compatible_surface: Some(&surface),
-            force_fallback_adapter: false,
+            force_fallback_adapter: config.force_fallback_adapter,
         };
 
         let adapter = match instance.request_adapter(&request_adapter_options).await {
             Some(adapter) => adapter,
             None => {
                 console::error_1(&"No suitable adapter found for WebGPU backend".into());
-                return Err("No suitable adapter found for WebGPU backend".into());
+                return Err(WebcgError::NoAdapter {
+                    backend: Backend::WebGpu,
+                });
             }
         };
 
         let device_descriptor = DeviceDescriptor {
             label: None,
-            required_features: Features::empty(),
-            required_limits: Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits()),
+            required_features: config.required_features,
+            required_limits: config.required_limits.clone().using_resolution(adapter.limits()),
             memory_hints: MemoryHints::MemoryUsage,
         };
 
@@ -88,7 +266,10 @@ impl<'a> WgpuApp<'a> {
             Ok((device, queue)) => (device, queue),
             Err(e) => {
                 console::error_1(&e.to_string().into());
-                return Err(Box::new(e));
+                return Err(WebcgError::DeviceRequest {
+                    backend: Backend::WebGpu,
+                    source: e,
+                });
             }
         };
 
@@ -100,10 +281,17 @@ impl<'a> WgpuApp<'a> {
             adapter,
             device,
             queue,
+            canvas: canvas_handle,
+            config: RefCell::new(None),
+            pipeline: RefCell::new(None),
+            desired_size: Cell::new((0, 0)),
         })
     }
 
-    pub async fn with_webgl_backend(canvas: HtmlCanvasElement) -> Result<Self, Box<dyn Error>> {
+    pub async fn with_webgl_backend(
+        canvas: HtmlCanvasElement,
+        config: &WgpuAppConfig,
+    ) -> Result<Self, WebcgError> {
         let instance_desc = wgpu::InstanceDescriptor {
             backends: wgpu::Backends::GL,
             flags: wgpu::InstanceFlags::empty(),
@@ -113,33 +301,39 @@ impl<'a> WgpuApp<'a> {
 
         let instance = wgpu::Instance::new(instance_desc);
 
+        let canvas_handle = canvas.clone();
         let surface_target: SurfaceTarget = SurfaceTarget::Canvas(canvas);
         let surface = match instance.create_surface(surface_target) {
             Ok(surface) => surface,
             Err(e) => {
                 console::error_1(&e.to_string().into());
-                return Err(Box::new(e));
+                return Err(WebcgError::SurfaceCreation {
+                    backend: Backend::WebGl,
+                    source: e,
+                });
             }
         };
 
         let request_adapter_options = wgpu::RequestAdapterOptions {
-            power_preference: PowerPreference::default(),
+            power_preference: config.power_preference,
             compatible_surface: Some(&surface),
-            force_fallback_adapter: false,
+            force_fallback_adapter: config.force_fallback_adapter,
         };
 
         let adapter = match instance.request_adapter(&request_adapter_options).await {
             Some(adapter) => adapter,
             None => {
                 console::error_1(&"No suitable adapter found for WebGL backend".into());
-                return Err("No suitable adapter found for WebGL backend".into());
+                return Err(WebcgError::NoAdapter {
+                    backend: Backend::WebGl,
+                });
             }
         };
 
         let device_descriptor = DeviceDescriptor {
             label: None,
-            required_features: Features::empty(),
-            required_limits: Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits()),
+            required_features: config.required_features,
+            required_limits: config.required_limits.clone().using_resolution(adapter.limits()),
             memory_hints: MemoryHints::MemoryUsage,
         };
 
@@ -147,7 +341,10 @@ impl<'a> WgpuApp<'a> {
             Ok((device, queue)) => (device, queue),
             Err(e) => {
                 console::error_1(&e.to_string().into());
-                return Err(Box::new(e));
+                return Err(WebcgError::DeviceRequest {
+                    backend: Backend::WebGl,
+                    source: e,
+                });
             }
         };
 
@@ -159,33 +356,293 @@ impl<'a> WgpuApp<'a> {
             adapter,
             device,
             queue,
+            canvas: canvas_handle,
+            config: RefCell::new(None),
+            pipeline: RefCell::new(None),
+            desired_size: Cell::new((0, 0)),
         })
     }
+
+    /// Returns information about the adapter that was selected, such as
+    /// its name and backend.
+    pub fn adapter_info(&self) -> wgpu::AdapterInfo {
+        self.adapter.get_info()
+    }
+
+    /// Returns the features the device was actually granted, i.e. the
+    /// `required_features` it was requested with, which may be a subset of
+    /// what the adapter reports.
+    pub fn supported_features(&self) -> Features {
+        self.device.features()
+    }
+
+    /// Returns the limits the device was actually granted.
+    pub fn supported_limits(&self) -> Limits {
+        self.device.limits()
+    }
+
+    /// Returns the first format reported by `Surface::get_capabilities`
+    /// for the current adapter, i.e. the format the surface is configured
+    /// with (or will be, once [`WgpuApp::configure_surface`] runs).
+    fn preferred_format(&self) -> wgpu::TextureFormat {
+        self.surface.get_capabilities(&self.adapter).formats[0]
+    }
+
+    /// Configures the surface for presentation at the given size, picking
+    /// the first format and present mode reported by
+    /// `Surface::get_capabilities` for the current adapter.
+    pub fn configure_surface(&self, width: u32, height: u32) {
+        let capabilities = self.surface.get_capabilities(&self.adapter);
+
+        let config = SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format: self.preferred_format(),
+            width,
+            height,
+            present_mode: capabilities.present_modes[0],
+            alpha_mode: capabilities.alpha_modes[0],
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+
+        self.surface.configure(&self.device, &config);
+        *self.config.borrow_mut() = Some(config);
+    }
+
+    /// Compiles `wgsl_source` into a `ShaderModule` and builds a
+    /// `RenderPipeline` targeting the surface's preferred format. Can be
+    /// called independently of [`WgpuApp::configure_surface`].
+    pub fn create_pipeline(&self, wgsl_source: &str) -> RenderPipeline {
+        let format = self.preferred_format();
+
+        let shader = self
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("webcg shader"),
+                source: wgpu::ShaderSource::Wgsl(wgsl_source.into()),
+            });
+
+        let layout = self
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("webcg pipeline layout"),
+                bind_group_layouts: &[],
+                push_constant_ranges: &[],
+            });
+
+        let pipeline = self
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("webcg render pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            });
+
+        *self.pipeline.borrow_mut() = Some(pipeline.clone());
+
+        pipeline
+    }
+
+    /// Acquires the next surface texture, clears it and draws with the
+    /// pipeline built by [`WgpuApp::create_pipeline`], then submits and
+    /// presents the frame. Panics if called before `create_pipeline`.
+    pub fn render(&self) -> Result<(), wgpu::SurfaceError> {
+        let pipeline_ref = self.pipeline.borrow();
+        let pipeline = pipeline_ref
+            .as_ref()
+            .expect("create_pipeline must be called before render");
+
+        let output = self.surface.get_current_texture()?;
+        let view = output
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("webcg render encoder"),
+            });
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("webcg render pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color {
+                            r: 0.1,
+                            g: 0.2,
+                            b: 0.3,
+                            a: 1.0,
+                        }),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_pipeline(pipeline);
+            render_pass.draw(0..3, 0..1);
+        }
+
+        self.queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+
+        Ok(())
+    }
 }
 
-async fn fetch(url: &str) -> Result<JsValue, JsValue> {
+impl WgpuApp<'static> {
+    /// Drives a continuous `requestAnimationFrame` loop: reconfigures the
+    /// surface whenever the canvas' displayed size changes (tracked via a
+    /// `resize` listener) and renders one frame on every tick. Surface
+    /// loss/outdated errors are recovered by reconfiguring and retrying on
+    /// the next frame. Consumes `self` since the loop owns the app for as
+    /// long as the page is alive.
+    pub fn run(self) {
+        let app = Rc::new(self);
+
+        let (width, height) = (
+            app.canvas.client_width().max(0) as u32,
+            app.canvas.client_height().max(0) as u32,
+        );
+        app.desired_size.set((width, height));
+        if width > 0 && height > 0 {
+            app.configure_surface(width, height);
+        }
+
+        let resize_app = app.clone();
+        let resize_closure = Closure::<dyn FnMut()>::new(move || {
+            let width = resize_app.canvas.client_width().max(0) as u32;
+            let height = resize_app.canvas.client_height().max(0) as u32;
+            resize_app.desired_size.set((width, height));
+        });
+        web_sys::window()
+            .expect("no global `window` exists")
+            .add_event_listener_with_callback("resize", resize_closure.as_ref().unchecked_ref())
+            .expect("failed to register resize listener");
+        resize_closure.forget();
+
+        let f: Rc<RefCell<Option<Closure<dyn FnMut()>>>> = Rc::new(RefCell::new(None));
+        let g = f.clone();
+        let frame_app = app.clone();
+
+        *g.borrow_mut() = Some(Closure::new(move || {
+            let (width, height) = frame_app.desired_size.get();
+            let needs_resize = frame_app
+                .config
+                .borrow()
+                .as_ref()
+                .map(|config| config.width != width || config.height != height)
+                .unwrap_or(true);
+
+            if needs_resize && width > 0 && height > 0 {
+                frame_app.configure_surface(width, height);
+            }
+
+            match frame_app.render() {
+                Ok(()) => (),
+                Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                    let (width, height) = frame_app.desired_size.get();
+                    frame_app.configure_surface(width, height);
+                }
+                Err(e) => console::error_1(&e.to_string().into()),
+            }
+
+            request_animation_frame(f.borrow().as_ref().unwrap());
+        }));
+
+        request_animation_frame(g.borrow().as_ref().unwrap());
+    }
+}
+
+fn request_animation_frame(closure: &Closure<dyn FnMut()>) {
+    web_sys::window()
+        .expect("no global `window` exists")
+        .request_animation_frame(closure.as_ref().unchecked_ref())
+        .expect("requestAnimationFrame should be available");
+}
+
+fn fetch_error(url: &str, reason: JsValue) -> WebcgError {
+    WebcgError::FetchFailed {
+        url: url.to_string(),
+        reason: format!("{reason:?}"),
+    }
+}
+
+/// Issues a GET request for `url` and returns the response, having
+/// already checked for a successful status code.
+async fn request(url: &str) -> Result<Response, WebcgError> {
     let opts = RequestInit::new();
     opts.set_method("GET");
     opts.set_mode(RequestMode::Cors);
 
-    let request = Request::new_with_str_and_init(url, &opts)?;
+    let req = Request::new_with_str_and_init(url, &opts).map_err(|e| fetch_error(url, e))?;
+
+    let window = web_sys::window().expect("no global `window` exists");
+    let resp_value = JsFuture::from(window.fetch_with_request(&req))
+        .await
+        .map_err(|e| fetch_error(url, e))?;
+
+    let response: Response = resp_value.dyn_into().map_err(|e| fetch_error(url, e))?;
+
+    if !response.ok() {
+        return Err(WebcgError::UnexpectedStatus {
+            url: url.to_string(),
+            status: response.status(),
+        });
+    }
+
+    Ok(response)
+}
 
-    request
-        .headers()
-        .set("Accept", "application/vnd.github.v3+json")?;
+/// Fetches `url` and decodes the response body as UTF-8 text. Suitable
+/// for loading WGSL shader sources and other text assets.
+pub async fn load_text(url: &str) -> Result<String, WebcgError> {
+    let response = request(url).await?;
 
-    let window = web_sys::window().unwrap();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
+    let text_promise = response.text().map_err(|e| fetch_error(url, e))?;
+    let text = JsFuture::from(text_promise)
+        .await
+        .map_err(|e| fetch_error(url, e))?;
 
-    // `resp_value` is a `Response` object.
-    assert!(resp_value.is_instance_of::<Response>());
-    let resp: Response = resp_value.dyn_into().unwrap();
+    Ok(text.as_string().unwrap_or_default())
+}
 
-    // Convert this other `Promise` into a rust `Future`.
-    let json = JsFuture::from(resp.text()?).await?;
+/// Fetches `url` and returns the response body as raw bytes. Suitable
+/// for loading textures and other binary assets.
+pub async fn load_bytes(url: &str) -> Result<Vec<u8>, WebcgError> {
+    let response = request(url).await?;
 
-    // Send the JSON response back to JS.
-    Ok(json)
+    let buffer_promise = response.array_buffer().map_err(|e| fetch_error(url, e))?;
+    let buffer = JsFuture::from(buffer_promise)
+        .await
+        .map_err(|e| fetch_error(url, e))?;
+
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
 }
 
 #[wasm_bindgen(start)]
@@ -196,12 +653,13 @@ async fn main() -> Result<(), JsValue> {
     let body = document.body().expect("Document should have a body");
 
     // Create a new WgpuApp
-    let app = WgpuApp::new(document, body).await?;
+    let app = WgpuApp::new(document, body, WgpuAppConfig::default()).await?;
 
     // Fetch shader.wgsl file
-    let shader_url = "shader.wgsl";
-    let shader = fetch(shader_url).await?;
-    console::log_1(&shader);
+    let shader_source = load_text("shader.wgsl").await?;
+
+    app.create_pipeline(&shader_source);
+    app.run();
 
     Ok(())
 }